@@ -49,7 +49,10 @@ const EXTERNAL_FILE_REGEX: &str = "^external/([^/]+)/";
 #[derive(Debug)]
 enum Mode {
     DirectoryBased(PathBuf),
-    ManifestBased(HashMap<PathBuf, PathBuf>),
+    ManifestBased {
+        manifest_path: PathBuf,
+        path_mapping: HashMap<PathBuf, PathBuf>,
+    },
 }
 
 #[derive(Debug)]
@@ -124,60 +127,135 @@ impl Runfiles {
 
     fn create_manifest_based() -> io::Result<Self> {
         let manifest_path = find_manifest_path()?;
-        let manifest_content = std::fs::read_to_string(manifest_path)?;
+        let manifest_content = std::fs::read_to_string(&manifest_path)?;
         let path_mapping = manifest_content
             .lines()
-            .map(|line| {
-                let pair = line
-                    .split_once(' ')
-                    .expect("manifest file contained unexpected content");
-                (pair.0.into(), pair.1.into())
-            })
+            .map(parse_manifest_line)
             .collect::<HashMap<_, _>>();
         Ok(Runfiles {
-            mode: Mode::ManifestBased(path_mapping),
+            mode: Mode::ManifestBased {
+                manifest_path,
+                path_mapping,
+            },
             repo_mapping: HashMap::new(),
             source_repository: Self::get_source_repository(),
         })
     }
 
+    /// Rebuilds this `Runfiles` to resolve paths as if they were requested by
+    /// `source_repository` instead of the repository of the running binary.
+    ///
+    /// This is useful for library code that lives in a different repository
+    /// than the binary that ultimately runs it: such code should look up its
+    /// own `current_repository()` and pass it here so that `@some_dep//:file`
+    /// style paths it requests are resolved relative to itself rather than to
+    /// the main binary.
+    pub fn with_source_repo(self, source_repository: String) -> Self {
+        Runfiles {
+            source_repository,
+            ..self
+        }
+    }
+
     /// Returns the runtime path of a runfile.
     ///
     /// Runfiles are data-dependencies of Bazel-built binaries and tests.
     /// The returned path may not be valid. The caller should check the path's
     /// validity and that the path exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `path` is not found among the runfiles of a manifest-based
+    /// `Runfiles`. Use [`Runfiles::rlocation_checked`] to probe for optional
+    /// runfiles instead.
     pub fn rlocation(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.rlocation_from(path, &self.source_repository)
+    }
+
+    /// Returns the runtime path of a runfile as if it were requested by
+    /// `source_repo` rather than by this `Runfiles`' own source repository.
+    ///
+    /// This lets library code resolve paths relative to its own repository
+    /// (obtained via `current_repository()`) instead of the repository of
+    /// whatever binary happens to be running, which matters once repo
+    /// mapping is involved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `path` is not found among the runfiles of a manifest-based
+    /// `Runfiles`. Use [`Runfiles::rlocation_checked_from`] to probe for
+    /// optional runfiles instead.
+    pub fn rlocation_from(&self, path: impl AsRef<Path>, source_repo: impl AsRef<str>) -> PathBuf {
+        let path = path.as_ref();
+        self.resolve(path, source_repo.as_ref())
+            .unwrap_or_else(|| panic!("Path {} not found among runfiles.", path.to_string_lossy()))
+    }
+
+    /// Like [`Runfiles::rlocation`], but returns an error instead of panicking
+    /// when `path` is not found among the runfiles of a manifest-based
+    /// `Runfiles`, so callers can probe for optional runfiles or surface a
+    /// clean error to the user.
+    ///
+    /// For a directory-based `Runfiles`, the join is always performed: the
+    /// caller is responsible for checking whether the resulting path exists.
+    pub fn rlocation_checked(&self, path: impl AsRef<Path>) -> io::Result<PathBuf> {
+        self.rlocation_checked_from(path, &self.source_repository)
+    }
+
+    /// Like [`Runfiles::rlocation_from`], but returns an error instead of
+    /// panicking when `path` is not found among the runfiles of a
+    /// manifest-based `Runfiles`.
+    pub fn rlocation_checked_from(
+        &self,
+        path: impl AsRef<Path>,
+        source_repo: impl AsRef<str>,
+    ) -> io::Result<PathBuf> {
         let path = path.as_ref();
+        self.resolve(path, source_repo.as_ref()).ok_or_else(|| {
+            make_io_error(&format!(
+                "Path {} not found among runfiles.",
+                path.to_string_lossy()
+            ))
+        })
+    }
+
+    /// Like [`Runfiles::rlocation_checked`], but returns `None` instead of
+    /// `Err(_)` when `path` is not found among the runfiles of a
+    /// manifest-based `Runfiles`.
+    pub fn rlocation_opt(&self, path: impl AsRef<Path>) -> Option<PathBuf> {
+        self.resolve(path.as_ref(), &self.source_repository)
+    }
 
+    /// Resolves `path` (requested by `source_repo`) to its runtime location,
+    /// returning `None` if a manifest-based lookup misses.
+    fn resolve(&self, path: &Path, source_repo: &str) -> Option<PathBuf> {
         if path.is_absolute() {
-            return path.to_path_buf();
+            return Some(path.to_path_buf());
         }
 
         let mut path_components = path.components();
-        let root = PathBuf::new().join(path_components.next().unwrap());
-        let remainder = path_components.as_path();
-
-        let repo_map_key = format!("{},{}", self.source_repository, root.to_string_lossy(),);
-        let repo_mapped_path = self
-            .repo_mapping
-            .get(&repo_map_key)
-            .cloned()
-            .map(|v| PathBuf::new().join(v).join(remainder));
-
-        let final_path = if let Some(repo_map_entry) = repo_mapped_path {
-            repo_map_entry
-        } else {
-            path.to_path_buf()
+        let final_path = match path_components.next() {
+            Some(first) => {
+                let root = PathBuf::new().join(first);
+                let remainder = path_components.as_path();
+
+                let repo_map_key = format!("{},{}", source_repo, root.to_string_lossy());
+                self.repo_mapping
+                    .get(&repo_map_key)
+                    .cloned()
+                    .map(|v| PathBuf::new().join(v).join(remainder))
+                    .unwrap_or_else(|| path.to_path_buf())
+            }
+            // An empty path has no apparent-repo segment to rewrite; let the
+            // mode below decide how to handle it rather than bailing out here.
+            None => path.to_path_buf(),
         };
 
         match &self.mode {
-            Mode::DirectoryBased(runfiles_dir) => runfiles_dir.join(final_path),
-            Mode::ManifestBased(path_mapping) => path_mapping
-                .get(final_path.as_path())
-                .unwrap_or_else(|| {
-                    panic!("Path {} not found among runfiles.", path.to_string_lossy())
-                })
-                .clone(),
+            Mode::DirectoryBased(runfiles_dir) => Some(runfiles_dir.join(final_path)),
+            Mode::ManifestBased { path_mapping, .. } => {
+                path_mapping.get(final_path.as_path()).cloned()
+            }
         }
     }
 
@@ -187,6 +265,29 @@ impl Runfiles {
         // which can be found in `@rules_rust//tools/runfiles/private:workspace_name.bzl`
         env!("RULES_RUST_RUNFILES_WORKSPACE_NAME")
     }
+
+    /// Returns the environment variables that should be set in a child
+    /// process so that it can locate the same runfiles tree as `self`,
+    /// regardless of how this process itself discovered it.
+    ///
+    /// This is useful when spawning a subprocess that itself uses a Bazel
+    /// runfiles library: splice these into `Command::envs` so the child can
+    /// resolve its own runfiles.
+    pub fn env_vars(&self) -> Vec<(OsString, OsString)> {
+        match &self.mode {
+            Mode::DirectoryBased(runfiles_dir) => vec![(
+                OsString::from(RUNFILES_DIR_ENV_VAR),
+                runfiles_dir.clone().into_os_string(),
+            )],
+            Mode::ManifestBased { manifest_path, .. } => vec![
+                (
+                    OsString::from(MANIFEST_FILE_ENV_VAR),
+                    manifest_path.clone().into_os_string(),
+                ),
+                (OsString::from(MANIFEST_ONLY_ENV_VAR), OsString::from("1")),
+            ],
+        }
+    }
 }
 
 /// Returns the .runfiles directory for the currently executing binary.
@@ -196,18 +297,44 @@ pub fn find_runfiles_dir() -> io::Result<PathBuf> {
         "1"
     );
 
-    // If bazel told us about the runfiles dir, use that without looking further.
+    // Prefer a runfiles directory shipped alongside the running binary. A
+    // binary that carries its own runfiles tree should use that tree even
+    // when it was launched as a data-dependency of another binary that
+    // exported RUNFILES_DIR/TEST_SRCDIR for itself. An I/O error while probing
+    // (e.g. argv[0] does not resolve to a file) counts as having none.
+    if let Ok(Some(runfiles_dir)) = find_runfiles_dir_relative_to_exe() {
+        return Ok(runfiles_dir);
+    }
+
+    // Fall back to what bazel told us about the runfiles dir.
+    if let Some(runfiles_dir) = find_runfiles_dir_from_env() {
+        return Ok(runfiles_dir);
+    }
+
+    Err(make_io_error("failed to find .runfiles directory"))
+}
+
+/// Returns the runfiles dir named by `RUNFILES_DIR`/`TEST_SRCDIR`, if either
+/// is set and points at a directory that exists. Split out from
+/// `find_runfiles_dir` so the env-var fallback can be unit tested without
+/// depending on the test binary's own runfiles dir.
+fn find_runfiles_dir_from_env() -> Option<PathBuf> {
     if let Some(runfiles_dir) = std::env::var_os(RUNFILES_DIR_ENV_VAR).map(PathBuf::from) {
         if runfiles_dir.is_dir() {
-            return Ok(runfiles_dir);
+            return Some(runfiles_dir);
         }
     }
     if let Some(test_srcdir) = std::env::var_os(TEST_SRCDIR_ENV_VAR).map(PathBuf::from) {
         if test_srcdir.is_dir() {
-            return Ok(test_srcdir);
+            return Some(test_srcdir);
         }
     }
+    None
+}
 
+/// Looks for a `.runfiles` directory next to the currently executing binary,
+/// following symlinks until one is found or the chain of links ends.
+fn find_runfiles_dir_relative_to_exe() -> io::Result<Option<PathBuf>> {
     // Consume the first argument (argv[0])
     let exec_path = std::env::args().next().expect("arg 0 was not set");
 
@@ -219,7 +346,7 @@ pub fn find_runfiles_dir() -> io::Result<PathBuf> {
 
         let runfiles_path = binary_path.with_file_name(&runfiles_name);
         if runfiles_path.is_dir() {
-            return Ok(runfiles_path);
+            return Ok(Some(runfiles_path));
         }
 
         // Check if we're already under a *.runfiles directory.
@@ -231,7 +358,7 @@ pub fn find_runfiles_dir() -> io::Result<PathBuf> {
                     .file_name()
                     .map_or(false, |f| f.to_string_lossy().ends_with(".runfiles"))
                 {
-                    return Ok(ancestor.to_path_buf());
+                    return Ok(Some(ancestor.to_path_buf()));
                 }
                 next = ancestor.parent();
             }
@@ -250,7 +377,71 @@ pub fn find_runfiles_dir() -> io::Result<PathBuf> {
         }
     }
 
-    Err(make_io_error("failed to find .runfiles directory"))
+    Ok(None)
+}
+
+/// Parses a single line of a Bazel runfiles MANIFEST file into its
+/// `(source, target)` path pair.
+///
+/// Lines normally look like `source target`, split on the first space. If a
+/// line begins with a space, it instead uses Bazel's escaped manifest format
+/// to support paths containing spaces or newlines: the source and target are
+/// still separated by the first *unescaped* space, and `\s`, `\n`, `\b` stand
+/// for a literal space, newline, and backslash respectively.
+fn parse_manifest_line(line: &str) -> (PathBuf, PathBuf) {
+    match line.strip_prefix(' ') {
+        Some(escaped) => {
+            let (source, target) = split_unescaped_space(escaped)
+                .expect("manifest file contained unexpected content");
+            (
+                unescape_manifest_path(source).into(),
+                unescape_manifest_path(target).into(),
+            )
+        }
+        None => {
+            let (source, target) = line
+                .split_once(' ')
+                .expect("manifest file contained unexpected content");
+            (source.into(), target.into())
+        }
+    }
+}
+
+/// Splits `s` on the first space that isn't escaped with a preceding `\`.
+fn split_unescaped_space(s: &str) -> Option<(&str, &str)> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b' ' => return Some((&s[..i], &s[i + 1..])),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Unescapes `\s`, `\n`, and `\b` as produced by Bazel's escaped manifest format.
+fn unescape_manifest_path(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('s') => result.push(' '),
+            Some('n') => result.push('\n'),
+            Some('b') => result.push('\\'),
+            Some(other) => {
+                result.push('\\');
+                result.push(other);
+            }
+            None => result.push('\\'),
+        }
+    }
+    result
 }
 
 fn make_io_error(msg: &str) -> io::Error {
@@ -269,6 +460,15 @@ fn find_manifest_path() -> io::Result<PathBuf> {
         std::env::var_os(MANIFEST_ONLY_ENV_VAR).expect("RUNFILES_MANIFEST_ONLY was not set"),
         OsString::from("1")
     );
+
+    // Prefer a manifest shipped alongside the running binary over one
+    // inherited from a parent process's environment variables, for the same
+    // reason find_runfiles_dir prefers its own neighboring runfiles dir. An
+    // I/O error while probing counts as having no neighboring manifest.
+    if let Ok(Some(manifest_path)) = find_manifest_path_relative_to_exe() {
+        return Ok(manifest_path);
+    }
+
     match std::env::var_os(MANIFEST_FILE_ENV_VAR) {
         Some(path) => Ok(path.into()),
         None => Err(
@@ -277,6 +477,45 @@ fn find_manifest_path() -> io::Result<PathBuf> {
     }
 }
 
+/// Looks for a `$binary.runfiles_manifest` file, or a `MANIFEST` file inside
+/// a `$binary.runfiles` directory, next to the currently executing binary,
+/// following symlinks until one is found or the chain of links ends.
+fn find_manifest_path_relative_to_exe() -> io::Result<Option<PathBuf>> {
+    // Consume the first argument (argv[0])
+    let exec_path = std::env::args().next().expect("arg 0 was not set");
+
+    let mut binary_path = PathBuf::from(&exec_path);
+    loop {
+        let mut manifest_name = binary_path.file_name().unwrap().to_owned();
+        manifest_name.push(".runfiles_manifest");
+        let manifest_path = binary_path.with_file_name(&manifest_name);
+        if manifest_path.is_file() {
+            return Ok(Some(manifest_path));
+        }
+
+        let mut runfiles_name = binary_path.file_name().unwrap().to_owned();
+        runfiles_name.push(".runfiles");
+        let runfiles_manifest_path = binary_path.with_file_name(&runfiles_name).join("MANIFEST");
+        if runfiles_manifest_path.is_file() {
+            return Ok(Some(runfiles_manifest_path));
+        }
+
+        if !fs::symlink_metadata(&binary_path)?.file_type().is_symlink() {
+            break;
+        }
+        // Follow symlinks and keep looking.
+        let link_target = binary_path.read_link()?;
+        binary_path = if link_target.is_absolute() {
+            link_target
+        } else {
+            let link_dir = binary_path.parent().unwrap();
+            env::current_dir()?.join(link_dir).join(link_target)
+        }
+    }
+
+    Ok(None)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -290,6 +529,12 @@ mod test {
         // environment variables are global state, we need to ensure the two test cases do not run
         // concurrently. Rust runs tests in parallel and does not provide an easy way to synchronise
         // them, so we run all test cases in the same #[test] function.
+        //
+        // Note: since `find_runfiles_dir` now prefers the test binary's own neighboring
+        // `.runfiles` dir over these env vars, cases 1-3 below resolve via that dir regardless
+        // of which env var is unset; they no longer exercise the env-var fallback itself. Case 4
+        // covers that fallback directly via `find_runfiles_dir_from_env`, in this same test
+        // function to avoid two tests racing on the same environment variables.
 
         let test_srcdir =
             env::var_os(TEST_SRCDIR_ENV_VAR).expect("bazel did not provide TEST_SRCDIR");
@@ -343,6 +588,31 @@ mod test {
             env::set_var(TEST_SRCDIR_ENV_VAR, &test_srcdir);
             env::set_var(RUNFILES_DIR_ENV_VAR, &runfiles_dir);
         }
+
+        // Test case 4: directly exercise the RUNFILES_DIR/TEST_SRCDIR fallback, since
+        // `find_runfiles_dir` itself never reaches it in the Bazel test sandbox (it always finds
+        // the test binary's own neighboring runfiles dir first).
+        {
+            env::remove_var(RUNFILES_DIR_ENV_VAR);
+            env::remove_var(TEST_SRCDIR_ENV_VAR);
+            assert_eq!(find_runfiles_dir_from_env(), None);
+
+            env::set_var(RUNFILES_DIR_ENV_VAR, &runfiles_dir);
+            assert_eq!(
+                find_runfiles_dir_from_env(),
+                Some(PathBuf::from(&runfiles_dir))
+            );
+            env::remove_var(RUNFILES_DIR_ENV_VAR);
+
+            env::set_var(TEST_SRCDIR_ENV_VAR, &test_srcdir);
+            assert_eq!(
+                find_runfiles_dir_from_env(),
+                Some(PathBuf::from(&test_srcdir))
+            );
+
+            env::set_var(TEST_SRCDIR_ENV_VAR, &test_srcdir);
+            env::set_var(RUNFILES_DIR_ENV_VAR, &runfiles_dir);
+        }
     }
 
     #[test]
@@ -350,7 +620,10 @@ mod test {
         let mut path_mapping = HashMap::new();
         path_mapping.insert("a/b".into(), "c/d".into());
         let r = Runfiles {
-            mode: Mode::ManifestBased(path_mapping),
+            mode: Mode::ManifestBased {
+                manifest_path: PathBuf::new(),
+                path_mapping,
+            },
             repo_mapping: HashMap::new(),
             source_repository: "".to_string(),
         };
@@ -358,6 +631,181 @@ mod test {
         assert_eq!(r.rlocation("a/b"), PathBuf::from("c/d"));
     }
 
+    #[test]
+    fn test_rlocation_rewrites_apparent_repo_via_repo_mapping() {
+        let mut repo_mapping = HashMap::new();
+        repo_mapping.insert(
+            "source_repo,apparent_repo".to_string(),
+            "canonical_repo~1.0".to_string(),
+        );
+        let r = Runfiles {
+            mode: Mode::DirectoryBased(PathBuf::from("/runfiles")),
+            repo_mapping,
+            source_repository: "source_repo".to_string(),
+        };
+
+        assert_eq!(
+            r.rlocation("apparent_repo/path/to/file.txt"),
+            PathBuf::from("/runfiles/canonical_repo~1.0/path/to/file.txt")
+        );
+    }
+
+    #[test]
+    fn test_rlocation_passes_through_when_repo_mapping_entry_absent() {
+        let r = Runfiles {
+            mode: Mode::DirectoryBased(PathBuf::from("/runfiles")),
+            repo_mapping: HashMap::new(),
+            source_repository: "source_repo".to_string(),
+        };
+
+        assert_eq!(
+            r.rlocation("already_canonical/path/to/file.txt"),
+            PathBuf::from("/runfiles/already_canonical/path/to/file.txt")
+        );
+    }
+
+    #[test]
+    fn test_rlocation_from_resolves_relative_to_explicit_source_repo() {
+        let mut repo_mapping = HashMap::new();
+        repo_mapping.insert(
+            "other_repo,apparent_repo".to_string(),
+            "canonical_repo".to_string(),
+        );
+        let r = Runfiles {
+            mode: Mode::DirectoryBased(PathBuf::from("/runfiles")),
+            repo_mapping,
+            source_repository: "source_repo".to_string(),
+        };
+
+        // `rlocation` uses `self.source_repository`, which has no matching entry.
+        assert_eq!(
+            r.rlocation("apparent_repo/file.txt"),
+            PathBuf::from("/runfiles/apparent_repo/file.txt")
+        );
+
+        // `rlocation_from` with the matching source repo rewrites it.
+        assert_eq!(
+            r.rlocation_from("apparent_repo/file.txt", "other_repo"),
+            PathBuf::from("/runfiles/canonical_repo/file.txt")
+        );
+    }
+
+    #[test]
+    fn test_env_vars_directory_based() {
+        let r = Runfiles {
+            mode: Mode::DirectoryBased(PathBuf::from("/runfiles")),
+            repo_mapping: HashMap::new(),
+            source_repository: "".to_string(),
+        };
+
+        assert_eq!(
+            r.env_vars(),
+            vec![(
+                OsString::from(RUNFILES_DIR_ENV_VAR),
+                OsString::from("/runfiles")
+            )]
+        );
+    }
+
+    #[test]
+    fn test_env_vars_manifest_based() {
+        let r = Runfiles {
+            mode: Mode::ManifestBased {
+                manifest_path: PathBuf::from("/runfiles_manifest/MANIFEST"),
+                path_mapping: HashMap::new(),
+            },
+            repo_mapping: HashMap::new(),
+            source_repository: "".to_string(),
+        };
+
+        assert_eq!(
+            r.env_vars(),
+            vec![
+                (
+                    OsString::from(MANIFEST_FILE_ENV_VAR),
+                    OsString::from("/runfiles_manifest/MANIFEST")
+                ),
+                (OsString::from(MANIFEST_ONLY_ENV_VAR), OsString::from("1")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_manifest_based_rlocation_checked_missing_path() {
+        let r = Runfiles {
+            mode: Mode::ManifestBased {
+                manifest_path: PathBuf::new(),
+                path_mapping: HashMap::new(),
+            },
+            repo_mapping: HashMap::new(),
+            source_repository: "".to_string(),
+        };
+
+        assert!(r.rlocation_checked("a/b").is_err());
+        assert_eq!(r.rlocation_opt("a/b"), None);
+    }
+
+    #[test]
+    fn test_rlocation_checked_empty_path_does_not_panic() {
+        let r = Runfiles {
+            mode: Mode::ManifestBased {
+                manifest_path: PathBuf::new(),
+                path_mapping: HashMap::new(),
+            },
+            repo_mapping: HashMap::new(),
+            source_repository: "".to_string(),
+        };
+
+        assert!(r.rlocation_checked("").is_err());
+        assert_eq!(r.rlocation_opt(""), None);
+    }
+
+    #[test]
+    fn test_directory_based_rlocation_checked_empty_path_still_joins() {
+        let r = Runfiles {
+            mode: Mode::DirectoryBased(PathBuf::from("/runfiles")),
+            repo_mapping: HashMap::new(),
+            source_repository: "".to_string(),
+        };
+
+        assert_eq!(r.rlocation_checked("").unwrap(), PathBuf::from("/runfiles"));
+        assert_eq!(r.rlocation_opt(""), Some(PathBuf::from("/runfiles")));
+    }
+
+    #[test]
+    fn test_parse_manifest_line_plain() {
+        assert_eq!(
+            parse_manifest_line("a/b c/d"),
+            (PathBuf::from("a/b"), PathBuf::from("c/d"))
+        );
+    }
+
+    #[test]
+    fn test_parse_manifest_line_escaped_embedded_spaces() {
+        assert_eq!(
+            parse_manifest_line(" a/b\\sc d/e\\sf"),
+            (PathBuf::from("a/b c"), PathBuf::from("d/e f"))
+        );
+    }
+
+    #[test]
+    fn test_parse_manifest_line_escaped_newline_and_backslash() {
+        assert_eq!(
+            parse_manifest_line(" a\\nb c\\bd"),
+            (PathBuf::from("a\nb"), PathBuf::from("c\\d"))
+        );
+    }
+
+    #[test]
+    fn test_parse_manifest_line_escaped_trailing_space_empty_target() {
+        // Bazel emits a trailing-space, target-less entry for empty files
+        // such as generated `__init__.py` markers.
+        assert_eq!(
+            parse_manifest_line(" a/b/__init__.py "),
+            (PathBuf::from("a/b/__init__.py"), PathBuf::from(""))
+        );
+    }
+
     #[test]
     fn test_current_repository() {
         let r = Runfiles::create().unwrap();